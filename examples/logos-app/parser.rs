@@ -19,7 +19,7 @@ pub enum Value {
     String(String),
     /// An array of values
     Array(Vec<Value>),
-    /// An dictionary mapping keys and values.
+    /// A dictionary mapping keys and values.
     Object(HashMap<String, Value>),
 }
 
@@ -182,3 +182,148 @@ fn parse_object(lexer: &mut Lexer<'_, Token>) -> Result<Value> {
     }
     Err(("unmatched opening brace defined here".to_owned(), span))
 }
+
+/// Represent any valid JSON value, borrowing strings from the source
+/// instead of copying them.
+///
+/// This mirrors [`Value`], but avoids the `to_owned` calls on every
+/// string token, which otherwise dominate parsing time for this lexer.
+#[derive(Debug)]
+pub enum BorrowedValue<'source> {
+    /// null.
+    Null,
+    /// true or false.
+    Bool(bool),
+    /// Any floating point number.
+    Number(f64),
+    /// Any quoted string, borrowed from the input.
+    String(&'source str),
+    /// An array of values
+    Array(Vec<BorrowedValue<'source>>),
+    /// A dictionary mapping keys and values, with borrowed keys.
+    Object(HashMap<&'source str, BorrowedValue<'source>>),
+}
+
+/// Parse a token stream into a borrowed JSON value.
+pub fn parse_borrowed_value<'source>(
+    lexer: &mut Lexer<'source, Token>,
+) -> Result<BorrowedValue<'source>> {
+    if let Some(token) = lexer.next() {
+        match token {
+            Ok(Token::True) => Ok(BorrowedValue::Bool(true)),
+            Ok(Token::False) => Ok(BorrowedValue::Bool(false)),
+            Ok(Token::BraceOpen) => parse_borrowed_object(lexer),
+            Ok(Token::BracketOpen) => parse_borrowed_array(lexer),
+            Ok(Token::Null) => Ok(BorrowedValue::Null),
+            Ok(Token::Number) => Ok(BorrowedValue::Number(lexer.slice().parse::<f64>().unwrap())),
+            Ok(Token::String) => Ok(BorrowedValue::String(lexer.slice())),
+            _ => Err((
+                "unexpected token here (context: value)".to_owned(),
+                lexer.span(),
+            )),
+        }
+    } else {
+        Err(("empty values are not allowed".to_owned(), lexer.span()))
+    }
+}
+
+/// Parse a token stream into a borrowed array and return when
+/// a valid terminator is found.
+///
+/// > NOTE: we assume '[' was consumed.
+fn parse_borrowed_array<'source>(
+    lexer: &mut Lexer<'source, Token>,
+) -> Result<BorrowedValue<'source>> {
+    let mut array = Vec::new();
+    let span = lexer.span();
+    let mut awaits_comma = false;
+    let mut awaits_value = false;
+
+    while let Some(token) = lexer.next() {
+        match token {
+            Ok(Token::True) if !awaits_comma => {
+                array.push(BorrowedValue::Bool(true));
+                awaits_value = false;
+            }
+            Ok(Token::False) if !awaits_comma => {
+                array.push(BorrowedValue::Bool(false));
+                awaits_value = false;
+            }
+            Ok(Token::BraceOpen) if !awaits_comma => {
+                let object = parse_borrowed_object(lexer)?;
+                array.push(object);
+                awaits_value = false;
+            }
+            Ok(Token::BracketOpen) if !awaits_comma => {
+                let sub_array = parse_borrowed_array(lexer)?;
+                array.push(sub_array);
+                awaits_value = false;
+            }
+            Ok(Token::BracketClose) if !awaits_value => return Ok(BorrowedValue::Array(array)),
+            Ok(Token::Comma) if awaits_comma => awaits_value = true,
+            Ok(Token::Null) if !awaits_comma => {
+                array.push(BorrowedValue::Null);
+                awaits_value = false
+            }
+            Ok(Token::Number) if !awaits_comma => {
+                array.push(BorrowedValue::Number(lexer.slice().parse::<f64>().unwrap()));
+                awaits_value = false;
+            }
+            Ok(Token::String) if !awaits_comma => {
+                array.push(BorrowedValue::String(lexer.slice()));
+                awaits_value = false;
+            }
+            _ => {
+                return Err((
+                    "unexpected token here (context: array)".to_owned(),
+                    lexer.span(),
+                ))
+            }
+        }
+        awaits_comma = !awaits_value;
+    }
+    Err(("unmatched opening bracket defined here".to_owned(), span))
+}
+
+/// Parse a token stream into a borrowed object and return when
+/// a valid terminator is found.
+///
+/// > NOTE: we assume '{' was consumed.
+fn parse_borrowed_object<'source>(
+    lexer: &mut Lexer<'source, Token>,
+) -> Result<BorrowedValue<'source>> {
+    let mut map = HashMap::new();
+    let span = lexer.span();
+    let mut awaits_comma = false;
+    let mut awaits_key = false;
+
+    while let Some(token) = lexer.next() {
+        match token {
+            Ok(Token::BraceClose) if !awaits_key => return Ok(BorrowedValue::Object(map)),
+            Ok(Token::Comma) if awaits_comma => awaits_key = true,
+            Ok(Token::String) if !awaits_comma => {
+                let key = lexer.slice();
+                match lexer.next() {
+                    Some(Ok(Token::Colon)) => (),
+                    _ => {
+                        return Err((
+                            "unexpected token here, expecting ':'".to_owned(),
+                            lexer.span(),
+                        ))
+                    }
+                }
+                let value = parse_borrowed_value(lexer)?;
+                map.insert(key, value);
+                awaits_key = false;
+            }
+            _ => {
+                return Err((
+                    "unexpected token here (context: object)".to_owned(),
+                    lexer.span(),
+                ))
+            }
+        }
+        awaits_comma = !awaits_key;
+    }
+    Err(("unmatched opening brace defined here".to_owned(), span))
+}